@@ -0,0 +1,66 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Enumerations shared across the model domain (instruments, orders, etc.).
+
+use serde::{Deserialize, Serialize};
+
+/// The asset class of an instrument.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub enum AssetClass {
+    FX,
+    Equity,
+    Commodity,
+    Debt,
+    Index,
+    Cryptocurrency,
+    /// Event/prediction-market style instruments with no underlying strike or curve.
+    Alternative,
+}
+
+/// The class of an instrument, describing its payoff/lifecycle structure.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub enum InstrumentClass {
+    Spot,
+    /// A perpetual (funded) swap, with no fixed expiration.
+    Swap,
+    Future,
+    Forward,
+    Option,
+    /// A fixed-payout option settled to either zero or `max_price` at expiry.
+    BinaryOption,
+}
+
+/// The kind of an option contract.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub enum OptionKind {
+    Call,
+    Put,
+}
@@ -0,0 +1,93 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Represents a valid price feed ID (identifies an external mark/index price source).
+
+use std::{
+    fmt::{Debug, Display, Formatter},
+    hash::Hash,
+};
+
+use nautilus_core::correctness::check_valid_string;
+use ustr::Ustr;
+
+/// Represents a valid price feed ID (identifies an external mark/index price source).
+#[repr(C)]
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+pub struct PriceFeedId(Ustr);
+
+impl PriceFeedId {
+    /// Creates a new [`PriceFeedId`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` is not a valid string.
+    pub fn new(value: &str) -> anyhow::Result<Self> {
+        check_valid_string(value, stringify!(value))?;
+
+        Ok(Self(Ustr::from(value)))
+    }
+
+    /// Returns the inner identifier value.
+    #[must_use]
+    pub fn inner(&self) -> Ustr {
+        self.0
+    }
+
+    /// Returns the inner identifier value as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl Debug for PriceFeedId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl Display for PriceFeedId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for PriceFeedId {
+    fn from(input: &str) -> Self {
+        Self::new(input).unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_string_reprs() {
+        let feed_id = PriceFeedId::from("BINANCE-BTCUSDT-MARK");
+        assert_eq!(feed_id.as_str(), "BINANCE-BTCUSDT-MARK");
+        assert_eq!(format!("{feed_id}"), "BINANCE-BTCUSDT-MARK");
+    }
+}
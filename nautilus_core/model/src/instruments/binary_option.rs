@@ -0,0 +1,370 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    any::Any,
+    hash::{Hash, Hasher},
+};
+
+use anyhow::{ensure, Result};
+use nautilus_core::time::UnixNanos;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::Instrument;
+use crate::{
+    enums::{AssetClass, InstrumentClass},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    types::{currency::Currency, price::Price, quantity::Quantity},
+};
+
+/// Represents a generic binary option instrument, with a fixed payout at expiry
+/// settled to either zero or `max_price` (e.g. event/prediction markets).
+#[repr(C)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct BinaryOption {
+    #[pyo3(get)]
+    pub id: InstrumentId,
+    #[pyo3(get)]
+    pub raw_symbol: Symbol,
+    pub outcome: Ustr,
+    #[pyo3(get)]
+    pub activation_ns: UnixNanos,
+    #[pyo3(get)]
+    pub expiration_ns: UnixNanos,
+    #[pyo3(get)]
+    pub currency: Currency,
+    #[pyo3(get)]
+    pub price_precision: u8,
+    #[pyo3(get)]
+    pub price_increment: Price,
+    #[pyo3(get)]
+    pub max_quantity: Option<Quantity>,
+    #[pyo3(get)]
+    pub min_quantity: Option<Quantity>,
+    #[pyo3(get)]
+    pub max_price: Option<Price>,
+    #[pyo3(get)]
+    pub min_price: Option<Price>,
+    #[pyo3(get)]
+    pub ts_event: UnixNanos,
+    #[pyo3(get)]
+    pub ts_init: UnixNanos,
+}
+
+impl BinaryOption {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        outcome: Ustr,
+        activation_ns: UnixNanos,
+        expiration_ns: UnixNanos,
+        currency: Currency,
+        price_precision: u8,
+        price_increment: Price,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Result<Self> {
+        Ok(Self {
+            id,
+            raw_symbol,
+            outcome,
+            activation_ns,
+            expiration_ns,
+            currency,
+            price_precision,
+            price_increment,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Returns a new [`BinaryOptionBuilder`] for fluently constructing a [`BinaryOption`].
+    #[must_use]
+    pub fn builder() -> BinaryOptionBuilder {
+        BinaryOptionBuilder::default()
+    }
+}
+
+/// A fluent builder for [`BinaryOption`], validating invariants on
+/// [`BinaryOptionBuilder::build`] rather than on every individual setter.
+#[derive(Debug, Default)]
+pub struct BinaryOptionBuilder {
+    id: Option<InstrumentId>,
+    raw_symbol: Option<Symbol>,
+    outcome: Option<Ustr>,
+    activation_ns: Option<UnixNanos>,
+    expiration_ns: Option<UnixNanos>,
+    currency: Option<Currency>,
+    price_precision: Option<u8>,
+    price_increment: Option<Price>,
+    max_quantity: Option<Quantity>,
+    min_quantity: Option<Quantity>,
+    max_price: Option<Price>,
+    min_price: Option<Price>,
+    ts_event: Option<UnixNanos>,
+    ts_init: Option<UnixNanos>,
+}
+
+impl BinaryOptionBuilder {
+    pub fn id(mut self, id: InstrumentId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn raw_symbol(mut self, raw_symbol: Symbol) -> Self {
+        self.raw_symbol = Some(raw_symbol);
+        self
+    }
+
+    pub fn outcome(mut self, outcome: Ustr) -> Self {
+        self.outcome = Some(outcome);
+        self
+    }
+
+    pub fn activation_ns(mut self, activation_ns: UnixNanos) -> Self {
+        self.activation_ns = Some(activation_ns);
+        self
+    }
+
+    pub fn expiration_ns(mut self, expiration_ns: UnixNanos) -> Self {
+        self.expiration_ns = Some(expiration_ns);
+        self
+    }
+
+    pub fn currency(mut self, currency: Currency) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    pub fn price_precision(mut self, price_precision: u8) -> Self {
+        self.price_precision = Some(price_precision);
+        self
+    }
+
+    pub fn price_increment(mut self, price_increment: Price) -> Self {
+        self.price_increment = Some(price_increment);
+        self
+    }
+
+    pub fn max_quantity(mut self, max_quantity: Quantity) -> Self {
+        self.max_quantity = Some(max_quantity);
+        self
+    }
+
+    pub fn min_quantity(mut self, min_quantity: Quantity) -> Self {
+        self.min_quantity = Some(min_quantity);
+        self
+    }
+
+    pub fn max_price(mut self, max_price: Price) -> Self {
+        self.max_price = Some(max_price);
+        self
+    }
+
+    pub fn min_price(mut self, min_price: Price) -> Self {
+        self.min_price = Some(min_price);
+        self
+    }
+
+    pub fn ts_event(mut self, ts_event: UnixNanos) -> Self {
+        self.ts_event = Some(ts_event);
+        self
+    }
+
+    pub fn ts_init(mut self, ts_init: UnixNanos) -> Self {
+        self.ts_init = Some(ts_init);
+        self
+    }
+
+    /// Validates the accumulated invariants and constructs the [`BinaryOption`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required field was never set, or if `expiration_ns` is not
+    /// greater than `activation_ns`, or if `min_price` is greater than `max_price`.
+    pub fn build(self) -> Result<BinaryOption> {
+        let activation_ns = self
+            .activation_ns
+            .ok_or_else(|| anyhow::anyhow!("`activation_ns` is required"))?;
+        let expiration_ns = self
+            .expiration_ns
+            .ok_or_else(|| anyhow::anyhow!("`expiration_ns` is required"))?;
+        ensure!(
+            expiration_ns > activation_ns,
+            "`expiration_ns` must be greater than `activation_ns`"
+        );
+        if let (Some(min_price), Some(max_price)) = (self.min_price, self.max_price) {
+            ensure!(
+                min_price <= max_price,
+                "`min_price` must be less than or equal to `max_price`"
+            );
+        }
+
+        BinaryOption::new(
+            self.id.ok_or_else(|| anyhow::anyhow!("`id` is required"))?,
+            self.raw_symbol
+                .ok_or_else(|| anyhow::anyhow!("`raw_symbol` is required"))?,
+            self.outcome
+                .ok_or_else(|| anyhow::anyhow!("`outcome` is required"))?,
+            activation_ns,
+            expiration_ns,
+            self.currency
+                .ok_or_else(|| anyhow::anyhow!("`currency` is required"))?,
+            self.price_precision
+                .ok_or_else(|| anyhow::anyhow!("`price_precision` is required"))?,
+            self.price_increment
+                .ok_or_else(|| anyhow::anyhow!("`price_increment` is required"))?,
+            self.max_quantity,
+            self.min_quantity,
+            self.max_price,
+            self.min_price,
+            self.ts_event.unwrap_or_default(),
+            self.ts_init.unwrap_or_default(),
+        )
+    }
+}
+
+impl PartialEq<Self> for BinaryOption {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for BinaryOption {}
+
+impl Hash for BinaryOption {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Instrument for BinaryOption {
+    fn id(&self) -> &InstrumentId {
+        &self.id
+    }
+
+    fn raw_symbol(&self) -> &Symbol {
+        &self.raw_symbol
+    }
+
+    fn asset_class(&self) -> AssetClass {
+        AssetClass::Alternative
+    }
+
+    fn instrument_class(&self) -> InstrumentClass {
+        InstrumentClass::BinaryOption
+    }
+
+    fn quote_currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    fn base_currency(&self) -> Option<&Currency> {
+        None
+    }
+
+    fn settlement_currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    fn is_inverse(&self) -> bool {
+        false
+    }
+
+    fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    fn size_precision(&self) -> u8 {
+        0
+    }
+
+    fn price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    fn size_increment(&self) -> Quantity {
+        Quantity::from(1)
+    }
+
+    fn multiplier(&self) -> Quantity {
+        // SAFETY: Unwrap safe as using known values
+        Quantity::new(1.0, 0).unwrap()
+    }
+
+    fn lot_size(&self) -> Option<Quantity> {
+        None
+    }
+
+    fn max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    fn min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    fn max_price(&self) -> Option<Price> {
+        self.max_price
+    }
+
+    fn min_price(&self) -> Option<Price> {
+        self.min_price
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::instruments::{binary_option::BinaryOption, stubs::*};
+
+    #[rstest]
+    fn test_equality(binary_option: BinaryOption) {
+        let cloned = binary_option.clone();
+        assert_eq!(binary_option, cloned);
+    }
+}
@@ -18,7 +18,7 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use nautilus_core::time::UnixNanos;
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -26,7 +26,7 @@ use serde::{Deserialize, Serialize};
 use super::Instrument;
 use crate::{
     enums::{AssetClass, InstrumentClass},
-    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    identifiers::{instrument_id::InstrumentId, price_feed_id::PriceFeedId, symbol::Symbol},
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
@@ -75,6 +75,8 @@ pub struct CryptoFuture {
     #[pyo3(get)]
     pub min_price: Option<Price>,
     #[pyo3(get)]
+    pub oracle_feed_id: Option<PriceFeedId>,
+    #[pyo3(get)]
     pub ts_event: UnixNanos,
     #[pyo3(get)]
     pub ts_init: UnixNanos,
@@ -123,10 +125,211 @@ impl CryptoFuture {
             min_notional,
             max_price,
             min_price,
+            oracle_feed_id: None,
             ts_event,
             ts_init,
         })
     }
+
+    /// Returns a new [`CryptoFutureBuilder`] for fluently constructing a [`CryptoFuture`].
+    #[must_use]
+    pub fn builder() -> CryptoFutureBuilder {
+        CryptoFutureBuilder::default()
+    }
+}
+
+/// A fluent builder for [`CryptoFuture`], validating invariants on [`CryptoFutureBuilder::build`]
+/// rather than on every individual setter.
+#[derive(Debug, Default)]
+pub struct CryptoFutureBuilder {
+    id: Option<InstrumentId>,
+    raw_symbol: Option<Symbol>,
+    underlying: Option<Currency>,
+    quote_currency: Option<Currency>,
+    settlement_currency: Option<Currency>,
+    activation_ns: Option<UnixNanos>,
+    expiration_ns: Option<UnixNanos>,
+    price_precision: Option<u8>,
+    size_precision: Option<u8>,
+    price_increment: Option<Price>,
+    size_increment: Option<Quantity>,
+    lot_size: Option<Quantity>,
+    max_quantity: Option<Quantity>,
+    min_quantity: Option<Quantity>,
+    max_notional: Option<Money>,
+    min_notional: Option<Money>,
+    max_price: Option<Price>,
+    min_price: Option<Price>,
+    oracle_feed_id: Option<PriceFeedId>,
+    ts_event: Option<UnixNanos>,
+    ts_init: Option<UnixNanos>,
+}
+
+impl CryptoFutureBuilder {
+    pub fn id(mut self, id: InstrumentId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn raw_symbol(mut self, raw_symbol: Symbol) -> Self {
+        self.raw_symbol = Some(raw_symbol);
+        self
+    }
+
+    pub fn underlying(mut self, underlying: Currency) -> Self {
+        self.underlying = Some(underlying);
+        self
+    }
+
+    pub fn quote_currency(mut self, quote_currency: Currency) -> Self {
+        self.quote_currency = Some(quote_currency);
+        self
+    }
+
+    pub fn settlement_currency(mut self, settlement_currency: Currency) -> Self {
+        self.settlement_currency = Some(settlement_currency);
+        self
+    }
+
+    pub fn activation_ns(mut self, activation_ns: UnixNanos) -> Self {
+        self.activation_ns = Some(activation_ns);
+        self
+    }
+
+    pub fn expiration_ns(mut self, expiration_ns: UnixNanos) -> Self {
+        self.expiration_ns = Some(expiration_ns);
+        self
+    }
+
+    pub fn price_precision(mut self, price_precision: u8) -> Self {
+        self.price_precision = Some(price_precision);
+        self
+    }
+
+    pub fn size_precision(mut self, size_precision: u8) -> Self {
+        self.size_precision = Some(size_precision);
+        self
+    }
+
+    pub fn price_increment(mut self, price_increment: Price) -> Self {
+        self.price_increment = Some(price_increment);
+        self
+    }
+
+    pub fn size_increment(mut self, size_increment: Quantity) -> Self {
+        self.size_increment = Some(size_increment);
+        self
+    }
+
+    pub fn lot_size(mut self, lot_size: Quantity) -> Self {
+        self.lot_size = Some(lot_size);
+        self
+    }
+
+    pub fn max_quantity(mut self, max_quantity: Quantity) -> Self {
+        self.max_quantity = Some(max_quantity);
+        self
+    }
+
+    pub fn min_quantity(mut self, min_quantity: Quantity) -> Self {
+        self.min_quantity = Some(min_quantity);
+        self
+    }
+
+    pub fn max_notional(mut self, max_notional: Money) -> Self {
+        self.max_notional = Some(max_notional);
+        self
+    }
+
+    pub fn min_notional(mut self, min_notional: Money) -> Self {
+        self.min_notional = Some(min_notional);
+        self
+    }
+
+    pub fn max_price(mut self, max_price: Price) -> Self {
+        self.max_price = Some(max_price);
+        self
+    }
+
+    pub fn min_price(mut self, min_price: Price) -> Self {
+        self.min_price = Some(min_price);
+        self
+    }
+
+    pub fn oracle_feed_id(mut self, oracle_feed_id: PriceFeedId) -> Self {
+        self.oracle_feed_id = Some(oracle_feed_id);
+        self
+    }
+
+    pub fn ts_event(mut self, ts_event: UnixNanos) -> Self {
+        self.ts_event = Some(ts_event);
+        self
+    }
+
+    pub fn ts_init(mut self, ts_init: UnixNanos) -> Self {
+        self.ts_init = Some(ts_init);
+        self
+    }
+
+    /// Validates the accumulated invariants and constructs the [`CryptoFuture`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required field was never set, or if `expiration_ns` is not
+    /// greater than `activation_ns`, or if `min_price` is greater than `max_price`.
+    pub fn build(self) -> Result<CryptoFuture> {
+        let activation_ns = self
+            .activation_ns
+            .ok_or_else(|| anyhow::anyhow!("`activation_ns` is required"))?;
+        let expiration_ns = self
+            .expiration_ns
+            .ok_or_else(|| anyhow::anyhow!("`expiration_ns` is required"))?;
+        ensure!(
+            expiration_ns > activation_ns,
+            "`expiration_ns` must be greater than `activation_ns`"
+        );
+        if let (Some(min_price), Some(max_price)) = (self.min_price, self.max_price) {
+            ensure!(
+                min_price <= max_price,
+                "`min_price` must be less than or equal to `max_price`"
+            );
+        }
+
+        CryptoFuture::new(
+            self.id.ok_or_else(|| anyhow::anyhow!("`id` is required"))?,
+            self.raw_symbol
+                .ok_or_else(|| anyhow::anyhow!("`raw_symbol` is required"))?,
+            self.underlying
+                .ok_or_else(|| anyhow::anyhow!("`underlying` is required"))?,
+            self.quote_currency
+                .ok_or_else(|| anyhow::anyhow!("`quote_currency` is required"))?,
+            self.settlement_currency
+                .ok_or_else(|| anyhow::anyhow!("`settlement_currency` is required"))?,
+            activation_ns,
+            expiration_ns,
+            self.price_precision
+                .ok_or_else(|| anyhow::anyhow!("`price_precision` is required"))?,
+            self.size_precision
+                .ok_or_else(|| anyhow::anyhow!("`size_precision` is required"))?,
+            self.price_increment
+                .ok_or_else(|| anyhow::anyhow!("`price_increment` is required"))?,
+            self.size_increment
+                .ok_or_else(|| anyhow::anyhow!("`size_increment` is required"))?,
+            self.lot_size,
+            self.max_quantity,
+            self.min_quantity,
+            self.max_notional,
+            self.min_notional,
+            self.max_price,
+            self.min_price,
+            self.ts_event.unwrap_or_default(),
+            self.ts_init.unwrap_or_default(),
+        )
+        .map(|mut instrument| {
+            instrument.oracle_feed_id = self.oracle_feed_id;
+            instrument
+        })
+    }
 }
 
 impl PartialEq<Self> for CryptoFuture {
@@ -228,6 +431,10 @@ impl Instrument for CryptoFuture {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn mark_price_feed(&self) -> Option<PriceFeedId> {
+        self.oracle_feed_id
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -244,4 +451,23 @@ mod tests {
         let cloned = crypto_future_btcusdt.clone();
         assert_eq!(crypto_future_btcusdt, cloned);
     }
-}
\ No newline at end of file
+
+    #[rstest]
+    fn test_builder_rejects_expiration_before_activation(crypto_future_btcusdt: CryptoFuture) {
+        let result = CryptoFuture::builder()
+            .id(crypto_future_btcusdt.id)
+            .raw_symbol(crypto_future_btcusdt.raw_symbol)
+            .underlying(crypto_future_btcusdt.underlying)
+            .quote_currency(crypto_future_btcusdt.quote_currency)
+            .settlement_currency(crypto_future_btcusdt.settlement_currency)
+            .activation_ns(crypto_future_btcusdt.expiration_ns)
+            .expiration_ns(crypto_future_btcusdt.activation_ns)
+            .price_precision(crypto_future_btcusdt.price_precision)
+            .size_precision(crypto_future_btcusdt.size_precision)
+            .price_increment(crypto_future_btcusdt.price_increment)
+            .size_increment(crypto_future_btcusdt.size_increment)
+            .build();
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,517 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    any::Any,
+    hash::{Hash, Hasher},
+};
+
+use anyhow::{ensure, Result};
+use nautilus_core::time::UnixNanos;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::Instrument;
+use crate::{
+    enums::{AssetClass, InstrumentClass},
+    identifiers::{instrument_id::InstrumentId, price_feed_id::PriceFeedId, symbol::Symbol},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// Represents a crypto perpetual swap instrument (a.k.a. perpetual future), typically
+/// trading with no expiration and funded periodically against a reference index.
+#[repr(C)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct CryptoPerpetual {
+    #[pyo3(get)]
+    pub id: InstrumentId,
+    #[pyo3(get)]
+    pub raw_symbol: Symbol,
+    #[pyo3(get)]
+    pub underlying: Currency,
+    #[pyo3(get)]
+    pub quote_currency: Currency,
+    #[pyo3(get)]
+    pub settlement_currency: Currency,
+    #[pyo3(get)]
+    pub is_inverse: bool,
+    #[pyo3(get)]
+    pub price_precision: u8,
+    #[pyo3(get)]
+    pub size_precision: u8,
+    #[pyo3(get)]
+    pub price_increment: Price,
+    #[pyo3(get)]
+    pub size_increment: Quantity,
+    #[pyo3(get)]
+    pub multiplier: Quantity,
+    #[pyo3(get)]
+    pub funding_interval_ns: UnixNanos,
+    #[pyo3(get)]
+    pub funding_rate: Option<Price>,
+    #[pyo3(get)]
+    pub lot_size: Option<Quantity>,
+    #[pyo3(get)]
+    pub max_quantity: Option<Quantity>,
+    #[pyo3(get)]
+    pub min_quantity: Option<Quantity>,
+    #[pyo3(get)]
+    pub max_notional: Option<Money>,
+    #[pyo3(get)]
+    pub min_notional: Option<Money>,
+    #[pyo3(get)]
+    pub max_price: Option<Price>,
+    #[pyo3(get)]
+    pub min_price: Option<Price>,
+    #[pyo3(get)]
+    pub oracle_feed_id: Option<PriceFeedId>,
+    #[pyo3(get)]
+    pub ts_event: UnixNanos,
+    #[pyo3(get)]
+    pub ts_init: UnixNanos,
+}
+
+impl CryptoPerpetual {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        underlying: Currency,
+        quote_currency: Currency,
+        settlement_currency: Currency,
+        is_inverse: bool,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        multiplier: Quantity,
+        funding_interval_ns: UnixNanos,
+        funding_rate: Option<Price>,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_notional: Option<Money>,
+        min_notional: Option<Money>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Result<Self> {
+        Ok(Self {
+            id,
+            raw_symbol,
+            underlying,
+            quote_currency,
+            settlement_currency,
+            is_inverse,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            multiplier,
+            funding_interval_ns,
+            funding_rate,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            oracle_feed_id: None,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Returns the funding payment owed on `position_notional` (in the settlement currency)
+    /// at the current `funding_rate`, given the instrument's `mark_price`.
+    ///
+    /// A positive result represents a payment from longs to shorts (and vice versa for a
+    /// negative result), consistent with the usual perpetual swap funding convention.
+    #[must_use]
+    pub fn calculate_funding_payment(
+        &self,
+        position_notional: f64,
+        mark_price: Price,
+    ) -> Option<f64> {
+        let funding_rate = self.funding_rate?;
+        if self.is_inverse {
+            Some(position_notional * funding_rate.as_f64() / mark_price.as_f64())
+        } else {
+            Some(position_notional * funding_rate.as_f64())
+        }
+    }
+
+    /// Returns a new [`CryptoPerpetualBuilder`] for fluently constructing a [`CryptoPerpetual`].
+    #[must_use]
+    pub fn builder() -> CryptoPerpetualBuilder {
+        CryptoPerpetualBuilder::default()
+    }
+}
+
+/// A fluent builder for [`CryptoPerpetual`], validating invariants on
+/// [`CryptoPerpetualBuilder::build`] rather than on every individual setter.
+#[derive(Debug, Default)]
+pub struct CryptoPerpetualBuilder {
+    id: Option<InstrumentId>,
+    raw_symbol: Option<Symbol>,
+    underlying: Option<Currency>,
+    quote_currency: Option<Currency>,
+    settlement_currency: Option<Currency>,
+    is_inverse: Option<bool>,
+    price_precision: Option<u8>,
+    size_precision: Option<u8>,
+    price_increment: Option<Price>,
+    size_increment: Option<Quantity>,
+    multiplier: Option<Quantity>,
+    funding_interval_ns: Option<UnixNanos>,
+    funding_rate: Option<Price>,
+    lot_size: Option<Quantity>,
+    max_quantity: Option<Quantity>,
+    min_quantity: Option<Quantity>,
+    max_notional: Option<Money>,
+    min_notional: Option<Money>,
+    max_price: Option<Price>,
+    min_price: Option<Price>,
+    oracle_feed_id: Option<PriceFeedId>,
+    ts_event: Option<UnixNanos>,
+    ts_init: Option<UnixNanos>,
+}
+
+impl CryptoPerpetualBuilder {
+    pub fn id(mut self, id: InstrumentId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn raw_symbol(mut self, raw_symbol: Symbol) -> Self {
+        self.raw_symbol = Some(raw_symbol);
+        self
+    }
+
+    pub fn underlying(mut self, underlying: Currency) -> Self {
+        self.underlying = Some(underlying);
+        self
+    }
+
+    pub fn quote_currency(mut self, quote_currency: Currency) -> Self {
+        self.quote_currency = Some(quote_currency);
+        self
+    }
+
+    pub fn settlement_currency(mut self, settlement_currency: Currency) -> Self {
+        self.settlement_currency = Some(settlement_currency);
+        self
+    }
+
+    pub fn is_inverse(mut self, is_inverse: bool) -> Self {
+        self.is_inverse = Some(is_inverse);
+        self
+    }
+
+    pub fn price_precision(mut self, price_precision: u8) -> Self {
+        self.price_precision = Some(price_precision);
+        self
+    }
+
+    pub fn size_precision(mut self, size_precision: u8) -> Self {
+        self.size_precision = Some(size_precision);
+        self
+    }
+
+    pub fn price_increment(mut self, price_increment: Price) -> Self {
+        self.price_increment = Some(price_increment);
+        self
+    }
+
+    pub fn size_increment(mut self, size_increment: Quantity) -> Self {
+        self.size_increment = Some(size_increment);
+        self
+    }
+
+    pub fn multiplier(mut self, multiplier: Quantity) -> Self {
+        self.multiplier = Some(multiplier);
+        self
+    }
+
+    pub fn funding_interval_ns(mut self, funding_interval_ns: UnixNanos) -> Self {
+        self.funding_interval_ns = Some(funding_interval_ns);
+        self
+    }
+
+    pub fn funding_rate(mut self, funding_rate: Price) -> Self {
+        self.funding_rate = Some(funding_rate);
+        self
+    }
+
+    pub fn lot_size(mut self, lot_size: Quantity) -> Self {
+        self.lot_size = Some(lot_size);
+        self
+    }
+
+    pub fn max_quantity(mut self, max_quantity: Quantity) -> Self {
+        self.max_quantity = Some(max_quantity);
+        self
+    }
+
+    pub fn min_quantity(mut self, min_quantity: Quantity) -> Self {
+        self.min_quantity = Some(min_quantity);
+        self
+    }
+
+    pub fn max_notional(mut self, max_notional: Money) -> Self {
+        self.max_notional = Some(max_notional);
+        self
+    }
+
+    pub fn min_notional(mut self, min_notional: Money) -> Self {
+        self.min_notional = Some(min_notional);
+        self
+    }
+
+    pub fn max_price(mut self, max_price: Price) -> Self {
+        self.max_price = Some(max_price);
+        self
+    }
+
+    pub fn min_price(mut self, min_price: Price) -> Self {
+        self.min_price = Some(min_price);
+        self
+    }
+
+    pub fn oracle_feed_id(mut self, oracle_feed_id: PriceFeedId) -> Self {
+        self.oracle_feed_id = Some(oracle_feed_id);
+        self
+    }
+
+    pub fn ts_event(mut self, ts_event: UnixNanos) -> Self {
+        self.ts_event = Some(ts_event);
+        self
+    }
+
+    pub fn ts_init(mut self, ts_init: UnixNanos) -> Self {
+        self.ts_init = Some(ts_init);
+        self
+    }
+
+    /// Validates the accumulated invariants and constructs the [`CryptoPerpetual`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required field was never set, or if `min_price` is greater
+    /// than `max_price`.
+    pub fn build(self) -> Result<CryptoPerpetual> {
+        if let (Some(min_price), Some(max_price)) = (self.min_price, self.max_price) {
+            ensure!(
+                min_price <= max_price,
+                "`min_price` must be less than or equal to `max_price`"
+            );
+        }
+
+        CryptoPerpetual::new(
+            self.id.ok_or_else(|| anyhow::anyhow!("`id` is required"))?,
+            self.raw_symbol
+                .ok_or_else(|| anyhow::anyhow!("`raw_symbol` is required"))?,
+            self.underlying
+                .ok_or_else(|| anyhow::anyhow!("`underlying` is required"))?,
+            self.quote_currency
+                .ok_or_else(|| anyhow::anyhow!("`quote_currency` is required"))?,
+            self.settlement_currency
+                .ok_or_else(|| anyhow::anyhow!("`settlement_currency` is required"))?,
+            self.is_inverse.unwrap_or(false),
+            self.price_precision
+                .ok_or_else(|| anyhow::anyhow!("`price_precision` is required"))?,
+            self.size_precision
+                .ok_or_else(|| anyhow::anyhow!("`size_precision` is required"))?,
+            self.price_increment
+                .ok_or_else(|| anyhow::anyhow!("`price_increment` is required"))?,
+            self.size_increment
+                .ok_or_else(|| anyhow::anyhow!("`size_increment` is required"))?,
+            self.multiplier
+                .ok_or_else(|| anyhow::anyhow!("`multiplier` is required"))?,
+            self.funding_interval_ns
+                .ok_or_else(|| anyhow::anyhow!("`funding_interval_ns` is required"))?,
+            self.funding_rate,
+            self.lot_size,
+            self.max_quantity,
+            self.min_quantity,
+            self.max_notional,
+            self.min_notional,
+            self.max_price,
+            self.min_price,
+            self.ts_event.unwrap_or_default(),
+            self.ts_init.unwrap_or_default(),
+        )
+        .map(|mut instrument| {
+            instrument.oracle_feed_id = self.oracle_feed_id;
+            instrument
+        })
+    }
+}
+
+impl PartialEq<Self> for CryptoPerpetual {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for CryptoPerpetual {}
+
+impl Hash for CryptoPerpetual {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Instrument for CryptoPerpetual {
+    fn id(&self) -> &InstrumentId {
+        &self.id
+    }
+
+    fn raw_symbol(&self) -> &Symbol {
+        &self.raw_symbol
+    }
+
+    fn asset_class(&self) -> AssetClass {
+        AssetClass::Cryptocurrency
+    }
+
+    fn instrument_class(&self) -> InstrumentClass {
+        InstrumentClass::Swap
+    }
+
+    fn quote_currency(&self) -> &Currency {
+        &self.quote_currency
+    }
+
+    fn base_currency(&self) -> Option<&Currency> {
+        None
+    }
+
+    fn settlement_currency(&self) -> &Currency {
+        &self.settlement_currency
+    }
+
+    fn is_inverse(&self) -> bool {
+        self.is_inverse
+    }
+
+    fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    fn size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    fn price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    fn size_increment(&self) -> Quantity {
+        self.size_increment
+    }
+
+    fn multiplier(&self) -> Quantity {
+        self.multiplier
+    }
+
+    fn lot_size(&self) -> Option<Quantity> {
+        self.lot_size
+    }
+
+    fn max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    fn min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    fn max_price(&self) -> Option<Price> {
+        self.max_price
+    }
+
+    fn min_price(&self) -> Option<Price> {
+        self.min_price
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn mark_price_feed(&self) -> Option<PriceFeedId> {
+        self.oracle_feed_id
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        instruments::{crypto_perpetual::CryptoPerpetual, stubs::*, Instrument},
+        types::price::Price,
+    };
+
+    #[rstest]
+    fn test_equality(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let cloned = crypto_perpetual_ethusdt.clone();
+        assert_eq!(crypto_perpetual_ethusdt, cloned);
+    }
+
+    #[rstest]
+    fn test_builder_rejects_min_price_above_max_price(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let result = CryptoPerpetual::builder()
+            .id(crypto_perpetual_ethusdt.id)
+            .raw_symbol(crypto_perpetual_ethusdt.raw_symbol)
+            .underlying(crypto_perpetual_ethusdt.underlying)
+            .quote_currency(crypto_perpetual_ethusdt.quote_currency)
+            .settlement_currency(crypto_perpetual_ethusdt.settlement_currency)
+            .price_precision(crypto_perpetual_ethusdt.price_precision)
+            .size_precision(crypto_perpetual_ethusdt.size_precision)
+            .price_increment(crypto_perpetual_ethusdt.price_increment)
+            .size_increment(crypto_perpetual_ethusdt.size_increment)
+            .multiplier(crypto_perpetual_ethusdt.multiplier)
+            .funding_interval_ns(crypto_perpetual_ethusdt.funding_interval_ns)
+            .min_price(Price::new(100.0, 2).unwrap())
+            .max_price(Price::new(1.0, 2).unwrap())
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_mark_price_feed_defaults_to_none(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        assert_eq!(crypto_perpetual_ethusdt.mark_price_feed(), None);
+    }
+}
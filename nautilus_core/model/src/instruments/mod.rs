@@ -0,0 +1,68 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tradable instrument definitions.
+
+pub mod binary_option;
+pub mod crypto_future;
+pub mod crypto_perpetual;
+pub mod options_contract;
+
+#[cfg(test)]
+pub mod stubs;
+
+use std::any::Any;
+
+use nautilus_core::time::UnixNanos;
+
+use crate::{
+    enums::{AssetClass, InstrumentClass},
+    identifiers::{
+        instrument_id::InstrumentId, price_feed_id::PriceFeedId, symbol::Symbol,
+    },
+    types::{currency::Currency, price::Price, quantity::Quantity},
+};
+
+/// Common behavior shared by all tradable instruments.
+pub trait Instrument: 'static + Send {
+    fn id(&self) -> &InstrumentId;
+    fn raw_symbol(&self) -> &Symbol;
+    fn asset_class(&self) -> AssetClass;
+    fn instrument_class(&self) -> InstrumentClass;
+    fn quote_currency(&self) -> &Currency;
+    fn base_currency(&self) -> Option<&Currency>;
+    fn settlement_currency(&self) -> &Currency;
+    fn is_inverse(&self) -> bool;
+    fn price_precision(&self) -> u8;
+    fn size_precision(&self) -> u8;
+    fn price_increment(&self) -> Price;
+    fn size_increment(&self) -> Quantity;
+    fn multiplier(&self) -> Quantity;
+    fn lot_size(&self) -> Option<Quantity>;
+    fn max_quantity(&self) -> Option<Quantity>;
+    fn min_quantity(&self) -> Option<Quantity>;
+    fn max_price(&self) -> Option<Price>;
+    fn min_price(&self) -> Option<Price>;
+    fn ts_event(&self) -> UnixNanos;
+    fn ts_init(&self) -> UnixNanos;
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns the identifier of the external mark/index price feed associated with this
+    /// instrument, if one has been configured. Defaults to `None` for instruments that
+    /// have no oracle binding.
+    fn mark_price_feed(&self) -> Option<PriceFeedId> {
+        None
+    }
+}
@@ -18,7 +18,7 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use nautilus_core::time::UnixNanos;
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -27,10 +27,42 @@ use ustr::Ustr;
 use super::Instrument;
 use crate::{
     enums::{AssetClass, InstrumentClass, OptionKind},
-    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    identifiers::{instrument_id::InstrumentId, price_feed_id::PriceFeedId, symbol::Symbol},
     types::{currency::Currency, price::Price, quantity::Quantity},
 };
 
+const NANOS_PER_YEAR: f64 = 365.25 * 24.0 * 60.0 * 60.0 * 1_000_000_000.0;
+
+/// Standard normal cumulative distribution function, via the Abramowitz–Stegun
+/// approximation to the error function (maximum absolute error ~1.5e-7).
+fn norm_cdf(x: f64) -> f64 {
+    0.5 * (1.0 + erf(x / std::f64::consts::SQRT_2))
+}
+
+/// Standard normal probability density function.
+fn norm_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+/// Abramowitz–Stegun approximation of the error function.
+fn erf(x: f64) -> f64 {
+    // Constants for the 7.1.26 approximation.
+    const A1: f64 = 0.254_829_592;
+    const A2: f64 = -0.284_496_736;
+    const A3: f64 = 1.421_413_741;
+    const A4: f64 = -1.453_152_027;
+    const A5: f64 = 1.061_405_429;
+    const P: f64 = 0.327_591_1;
+
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+
+    let t = 1.0 / (1.0 + P * x);
+    let y = 1.0 - (((((A5 * t + A4) * t) + A3) * t + A2) * t + A1) * t * (-x * x).exp();
+
+    sign * y
+}
+
 #[repr(C)]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(
@@ -73,6 +105,8 @@ pub struct OptionsContract {
     #[pyo3(get)]
     pub min_price: Option<Price>,
     #[pyo3(get)]
+    pub oracle_feed_id: Option<PriceFeedId>,
+    #[pyo3(get)]
     pub ts_event: UnixNanos,
     #[pyo3(get)]
     pub ts_init: UnixNanos,
@@ -119,10 +153,346 @@ impl OptionsContract {
             min_quantity,
             max_price,
             min_price,
+            oracle_feed_id: None,
             ts_event,
             ts_init,
         })
     }
+
+    /// Returns the time to expiry in years from `now_ns`, or `0.0` if already expired.
+    #[must_use]
+    pub fn time_to_expiry_years(&self, now_ns: UnixNanos) -> f64 {
+        let expiration_ns = self.expiration_ns.as_u64();
+        let now_ns = now_ns.as_u64();
+        if expiration_ns <= now_ns {
+            return 0.0;
+        }
+        (expiration_ns - now_ns) as f64 / NANOS_PER_YEAR
+    }
+
+    /// Returns the intrinsic value of the contract given `spot`, ignoring time value.
+    #[must_use]
+    pub fn intrinsic_value(&self, spot: f64) -> f64 {
+        let strike = self.strike_price.as_f64();
+        let value = match self.option_kind {
+            OptionKind::Call => (spot - strike).max(0.0),
+            OptionKind::Put => (strike - spot).max(0.0),
+        };
+        value * self.multiplier.as_f64()
+    }
+
+    /// Computes the theoretical Black–Scholes price of the option.
+    ///
+    /// Returns the (multiplier-scaled) intrinsic value when the option has expired
+    /// (`T <= 0`) or `vol <= 0`.
+    #[must_use]
+    pub fn black_scholes_price(&self, spot: f64, vol: f64, rate: f64, now_ns: UnixNanos) -> f64 {
+        let t = self.time_to_expiry_years(now_ns);
+        if t <= 0.0 || vol <= 0.0 {
+            return self.intrinsic_value(spot);
+        }
+
+        let strike = self.strike_price.as_f64();
+        let (d1, d2) = self.d1_d2(spot, strike, vol, rate, t);
+        let discount = (-rate * t).exp();
+
+        let price = match self.option_kind {
+            OptionKind::Call => spot * norm_cdf(d1) - strike * discount * norm_cdf(d2),
+            OptionKind::Put => strike * discount * norm_cdf(-d2) - spot * norm_cdf(-d1),
+        };
+
+        price * self.multiplier.as_f64()
+    }
+
+    /// Computes the option's delta (sensitivity of price to a unit change in `spot`).
+    #[must_use]
+    pub fn delta(&self, spot: f64, vol: f64, rate: f64, now_ns: UnixNanos) -> f64 {
+        let t = self.time_to_expiry_years(now_ns);
+        if t <= 0.0 || vol <= 0.0 {
+            return 0.0;
+        }
+
+        let strike = self.strike_price.as_f64();
+        let (d1, _) = self.d1_d2(spot, strike, vol, rate, t);
+
+        let delta = match self.option_kind {
+            OptionKind::Call => norm_cdf(d1),
+            OptionKind::Put => norm_cdf(d1) - 1.0,
+        };
+
+        delta * self.multiplier.as_f64()
+    }
+
+    /// Computes the option's gamma (sensitivity of delta to a unit change in `spot`).
+    #[must_use]
+    pub fn gamma(&self, spot: f64, vol: f64, rate: f64, now_ns: UnixNanos) -> f64 {
+        let t = self.time_to_expiry_years(now_ns);
+        if t <= 0.0 || vol <= 0.0 {
+            return 0.0;
+        }
+
+        let strike = self.strike_price.as_f64();
+        let (d1, _) = self.d1_d2(spot, strike, vol, rate, t);
+
+        norm_pdf(d1) / (spot * vol * t.sqrt()) * self.multiplier.as_f64()
+    }
+
+    /// Computes the option's vega (sensitivity of price to a unit change in `vol`).
+    #[must_use]
+    pub fn vega(&self, spot: f64, vol: f64, rate: f64, now_ns: UnixNanos) -> f64 {
+        let t = self.time_to_expiry_years(now_ns);
+        if t <= 0.0 || vol <= 0.0 {
+            return 0.0;
+        }
+
+        let strike = self.strike_price.as_f64();
+        let (d1, _) = self.d1_d2(spot, strike, vol, rate, t);
+
+        spot * norm_pdf(d1) * t.sqrt() * self.multiplier.as_f64()
+    }
+
+    /// Computes the option's theta (sensitivity of price to the passage of time, per year).
+    #[must_use]
+    pub fn theta(&self, spot: f64, vol: f64, rate: f64, now_ns: UnixNanos) -> f64 {
+        let t = self.time_to_expiry_years(now_ns);
+        if t <= 0.0 || vol <= 0.0 {
+            return 0.0;
+        }
+
+        let strike = self.strike_price.as_f64();
+        let (d1, d2) = self.d1_d2(spot, strike, vol, rate, t);
+        let discount = (-rate * t).exp();
+        let decay = -(spot * norm_pdf(d1) * vol) / (2.0 * t.sqrt());
+
+        let theta = match self.option_kind {
+            OptionKind::Call => decay - rate * strike * discount * norm_cdf(d2),
+            OptionKind::Put => decay + rate * strike * discount * norm_cdf(-d2),
+        };
+
+        theta * self.multiplier.as_f64()
+    }
+
+    /// Computes the option's rho (sensitivity of price to a unit change in `rate`).
+    #[must_use]
+    pub fn rho(&self, spot: f64, vol: f64, rate: f64, now_ns: UnixNanos) -> f64 {
+        let t = self.time_to_expiry_years(now_ns);
+        if t <= 0.0 || vol <= 0.0 {
+            return 0.0;
+        }
+
+        let strike = self.strike_price.as_f64();
+        let (_, d2) = self.d1_d2(spot, strike, vol, rate, t);
+        let discount = (-rate * t).exp();
+
+        let rho = match self.option_kind {
+            OptionKind::Call => strike * t * discount * norm_cdf(d2),
+            OptionKind::Put => -strike * t * discount * norm_cdf(-d2),
+        };
+
+        rho * self.multiplier.as_f64()
+    }
+
+    /// Computes the `d1`/`d2` terms shared by the Black–Scholes price and Greeks.
+    fn d1_d2(&self, spot: f64, strike: f64, vol: f64, rate: f64, t: f64) -> (f64, f64) {
+        let d1 = ((spot / strike).ln() + (rate + 0.5 * vol * vol) * t) / (vol * t.sqrt());
+        let d2 = d1 - vol * t.sqrt();
+        (d1, d2)
+    }
+
+    /// Returns a new [`OptionsContractBuilder`] for fluently constructing an [`OptionsContract`].
+    #[must_use]
+    pub fn builder() -> OptionsContractBuilder {
+        OptionsContractBuilder::default()
+    }
+}
+
+/// A fluent builder for [`OptionsContract`], validating invariants on
+/// [`OptionsContractBuilder::build`] rather than on every individual setter.
+#[derive(Debug, Default)]
+pub struct OptionsContractBuilder {
+    id: Option<InstrumentId>,
+    raw_symbol: Option<Symbol>,
+    asset_class: Option<AssetClass>,
+    underlying: Option<Ustr>,
+    option_kind: Option<OptionKind>,
+    activation_ns: Option<UnixNanos>,
+    expiration_ns: Option<UnixNanos>,
+    strike_price: Option<Price>,
+    currency: Option<Currency>,
+    price_precision: Option<u8>,
+    price_increment: Option<Price>,
+    multiplier: Option<Quantity>,
+    lot_size: Option<Quantity>,
+    max_quantity: Option<Quantity>,
+    min_quantity: Option<Quantity>,
+    max_price: Option<Price>,
+    min_price: Option<Price>,
+    oracle_feed_id: Option<PriceFeedId>,
+    ts_event: Option<UnixNanos>,
+    ts_init: Option<UnixNanos>,
+}
+
+impl OptionsContractBuilder {
+    pub fn id(mut self, id: InstrumentId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn raw_symbol(mut self, raw_symbol: Symbol) -> Self {
+        self.raw_symbol = Some(raw_symbol);
+        self
+    }
+
+    pub fn asset_class(mut self, asset_class: AssetClass) -> Self {
+        self.asset_class = Some(asset_class);
+        self
+    }
+
+    pub fn underlying(mut self, underlying: Ustr) -> Self {
+        self.underlying = Some(underlying);
+        self
+    }
+
+    pub fn option_kind(mut self, option_kind: OptionKind) -> Self {
+        self.option_kind = Some(option_kind);
+        self
+    }
+
+    pub fn activation_ns(mut self, activation_ns: UnixNanos) -> Self {
+        self.activation_ns = Some(activation_ns);
+        self
+    }
+
+    pub fn expiration_ns(mut self, expiration_ns: UnixNanos) -> Self {
+        self.expiration_ns = Some(expiration_ns);
+        self
+    }
+
+    pub fn strike_price(mut self, strike_price: Price) -> Self {
+        self.strike_price = Some(strike_price);
+        self
+    }
+
+    pub fn currency(mut self, currency: Currency) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    pub fn price_precision(mut self, price_precision: u8) -> Self {
+        self.price_precision = Some(price_precision);
+        self
+    }
+
+    pub fn price_increment(mut self, price_increment: Price) -> Self {
+        self.price_increment = Some(price_increment);
+        self
+    }
+
+    pub fn multiplier(mut self, multiplier: Quantity) -> Self {
+        self.multiplier = Some(multiplier);
+        self
+    }
+
+    pub fn lot_size(mut self, lot_size: Quantity) -> Self {
+        self.lot_size = Some(lot_size);
+        self
+    }
+
+    pub fn max_quantity(mut self, max_quantity: Quantity) -> Self {
+        self.max_quantity = Some(max_quantity);
+        self
+    }
+
+    pub fn min_quantity(mut self, min_quantity: Quantity) -> Self {
+        self.min_quantity = Some(min_quantity);
+        self
+    }
+
+    pub fn max_price(mut self, max_price: Price) -> Self {
+        self.max_price = Some(max_price);
+        self
+    }
+
+    pub fn min_price(mut self, min_price: Price) -> Self {
+        self.min_price = Some(min_price);
+        self
+    }
+
+    pub fn oracle_feed_id(mut self, oracle_feed_id: PriceFeedId) -> Self {
+        self.oracle_feed_id = Some(oracle_feed_id);
+        self
+    }
+
+    pub fn ts_event(mut self, ts_event: UnixNanos) -> Self {
+        self.ts_event = Some(ts_event);
+        self
+    }
+
+    pub fn ts_init(mut self, ts_init: UnixNanos) -> Self {
+        self.ts_init = Some(ts_init);
+        self
+    }
+
+    /// Validates the accumulated invariants and constructs the [`OptionsContract`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required field was never set, or if `expiration_ns` is not
+    /// greater than `activation_ns`, or if `min_price` is greater than `max_price`.
+    pub fn build(self) -> Result<OptionsContract> {
+        let activation_ns = self
+            .activation_ns
+            .ok_or_else(|| anyhow::anyhow!("`activation_ns` is required"))?;
+        let expiration_ns = self
+            .expiration_ns
+            .ok_or_else(|| anyhow::anyhow!("`expiration_ns` is required"))?;
+        ensure!(
+            expiration_ns > activation_ns,
+            "`expiration_ns` must be greater than `activation_ns`"
+        );
+        if let (Some(min_price), Some(max_price)) = (self.min_price, self.max_price) {
+            ensure!(
+                min_price <= max_price,
+                "`min_price` must be less than or equal to `max_price`"
+            );
+        }
+
+        OptionsContract::new(
+            self.id.ok_or_else(|| anyhow::anyhow!("`id` is required"))?,
+            self.raw_symbol
+                .ok_or_else(|| anyhow::anyhow!("`raw_symbol` is required"))?,
+            self.asset_class
+                .ok_or_else(|| anyhow::anyhow!("`asset_class` is required"))?,
+            self.underlying
+                .ok_or_else(|| anyhow::anyhow!("`underlying` is required"))?,
+            self.option_kind
+                .ok_or_else(|| anyhow::anyhow!("`option_kind` is required"))?,
+            activation_ns,
+            expiration_ns,
+            self.strike_price
+                .ok_or_else(|| anyhow::anyhow!("`strike_price` is required"))?,
+            self.currency
+                .ok_or_else(|| anyhow::anyhow!("`currency` is required"))?,
+            self.price_precision
+                .ok_or_else(|| anyhow::anyhow!("`price_precision` is required"))?,
+            self.price_increment
+                .ok_or_else(|| anyhow::anyhow!("`price_increment` is required"))?,
+            self.multiplier
+                .ok_or_else(|| anyhow::anyhow!("`multiplier` is required"))?,
+            self.lot_size
+                .ok_or_else(|| anyhow::anyhow!("`lot_size` is required"))?,
+            self.max_quantity,
+            self.min_quantity,
+            self.max_price,
+            self.min_price,
+            self.ts_event.unwrap_or_default(),
+            self.ts_init.unwrap_or_default(),
+        )
+        .map(|mut instrument| {
+            instrument.oracle_feed_id = self.oracle_feed_id;
+            instrument
+        })
+    }
 }
 
 impl PartialEq<Self> for OptionsContract {
@@ -223,6 +593,10 @@ impl Instrument for OptionsContract {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn mark_price_feed(&self) -> Option<PriceFeedId> {
+        self.oracle_feed_id
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -239,4 +613,60 @@ mod tests {
         let options_contract_appl2 = options_contract_appl.clone();
         assert_eq!(options_contract_appl, options_contract_appl2);
     }
-}
\ No newline at end of file
+
+    #[rstest]
+    fn test_black_scholes_price_call_put_parity(options_contract_appl: OptionsContract) {
+        let spot = options_contract_appl.strike_price.as_f64();
+        let vol = 0.2;
+        let rate = 0.01;
+        let now_ns = options_contract_appl.activation_ns;
+
+        let mut call = options_contract_appl.clone();
+        call.option_kind = crate::enums::OptionKind::Call;
+        let mut put = options_contract_appl.clone();
+        put.option_kind = crate::enums::OptionKind::Put;
+
+        let call_price = call.black_scholes_price(spot, vol, rate, now_ns);
+        let put_price = put.black_scholes_price(spot, vol, rate, now_ns);
+
+        // Put-call parity: C - P = (S - K * e^{-rT}) * multiplier.
+        let t = call.time_to_expiry_years(now_ns);
+        let strike = call.strike_price.as_f64();
+        let expected = (spot - strike * (-rate * t).exp()) * call.multiplier.as_f64();
+
+        assert!((call_price - put_price - expected).abs() < 1e-6);
+    }
+
+    #[rstest]
+    fn test_black_scholes_price_expired_returns_intrinsic(options_contract_appl: OptionsContract) {
+        let mut expired = options_contract_appl.clone();
+        expired.option_kind = crate::enums::OptionKind::Call;
+
+        let price = expired.black_scholes_price(
+            expired.strike_price.as_f64() + 10.0,
+            0.2,
+            0.01,
+            expired.expiration_ns,
+        );
+
+        assert_eq!(
+            price,
+            expired.intrinsic_value(expired.strike_price.as_f64() + 10.0)
+        );
+    }
+
+    #[rstest]
+    fn test_delta_bounds(options_contract_appl: OptionsContract) {
+        let mut call = options_contract_appl.clone();
+        call.option_kind = crate::enums::OptionKind::Call;
+
+        let delta = call.delta(
+            call.strike_price.as_f64(),
+            0.2,
+            0.01,
+            call.activation_ns,
+        );
+
+        assert!(delta > 0.0 && delta < call.multiplier.as_f64());
+    }
+}
@@ -0,0 +1,133 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Instrument test fixtures shared across the `instruments` module's test suites.
+
+use nautilus_core::time::UnixNanos;
+use rstest::fixture;
+use ustr::Ustr;
+
+use crate::{
+    enums::OptionKind,
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    instruments::{
+        binary_option::BinaryOption, crypto_future::CryptoFuture,
+        crypto_perpetual::CryptoPerpetual, options_contract::OptionsContract,
+    },
+    types::{currency::Currency, price::Price, quantity::Quantity},
+};
+
+#[fixture]
+pub fn crypto_future_btcusdt() -> CryptoFuture {
+    CryptoFuture::new(
+        InstrumentId::from("BTCUSDT_230630.BINANCE"),
+        Symbol::from("BTCUSDT_230630"),
+        Currency::from("BTC"),
+        Currency::from("USDT"),
+        Currency::from("USDT"),
+        UnixNanos::default(),
+        UnixNanos::from(1_688_097_600_000_000_000),
+        2,
+        6,
+        Price::new(0.01, 2).unwrap(),
+        Quantity::new(0.000001, 6).unwrap(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+    .unwrap()
+}
+
+#[fixture]
+pub fn options_contract_appl() -> OptionsContract {
+    OptionsContract::new(
+        InstrumentId::from("AAPL230721C00150000.OPRA"),
+        Symbol::from("AAPL230721C00150000"),
+        crate::enums::AssetClass::Equity,
+        Ustr::from("AAPL"),
+        OptionKind::Call,
+        UnixNanos::default(),
+        UnixNanos::from(1_689_951_600_000_000_000),
+        Price::new(150.0, 2).unwrap(),
+        Currency::from("USD"),
+        2,
+        Price::new(0.01, 2).unwrap(),
+        Quantity::new(100.0, 0).unwrap(),
+        Quantity::new(1.0, 0).unwrap(),
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+    .unwrap()
+}
+
+#[fixture]
+pub fn crypto_perpetual_ethusdt() -> CryptoPerpetual {
+    CryptoPerpetual::new(
+        InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+        Symbol::from("ETHUSDT-PERP"),
+        Currency::from("ETH"),
+        Currency::from("USDT"),
+        Currency::from("USDT"),
+        false,
+        2,
+        3,
+        Price::new(0.01, 2).unwrap(),
+        Quantity::new(0.001, 3).unwrap(),
+        Quantity::new(1.0, 0).unwrap(),
+        UnixNanos::from(28_800_000_000_000),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+    .unwrap()
+}
+
+#[fixture]
+pub fn binary_option() -> BinaryOption {
+    BinaryOption::new(
+        InstrumentId::from("0xdeadbeef-will-btc-close-above-50k.POLYMARKET"),
+        Symbol::from("0xdeadbeef-will-btc-close-above-50k"),
+        Ustr::from("Will BTC close above $50k on 2024-12-31?"),
+        UnixNanos::default(),
+        UnixNanos::from(1_735_689_600_000_000_000),
+        Currency::from("USD"),
+        2,
+        Price::new(0.01, 2).unwrap(),
+        None,
+        None,
+        Some(Price::new(1.0, 2).unwrap()),
+        Some(Price::new(0.0, 2).unwrap()),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+    .unwrap()
+}